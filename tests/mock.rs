@@ -8,7 +8,7 @@
 
 use dusk_bls12_381_sign::PublicKey;
 use dusk_jubjub::{BlsScalar, JubJubAffine, JubJubScalar};
-use dusk_pki::{PublicSpendKey, ViewKey};
+use dusk_pki::{PublicSpendKey, SecretSpendKey, ViewKey};
 use dusk_plonk::prelude::Proof;
 use dusk_schnorr::Signature;
 use dusk_wallet_core::{
@@ -18,6 +18,9 @@ use dusk_wallet_core::{
 use phoenix_core::{Crossover, Fee, Note, NoteType};
 use poseidon_merkle::{Item, Opening as PoseidonOpening, Tree};
 use rand_core::{CryptoRng, RngCore};
+use std::cell::RefCell;
+use std::rc::Rc;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 fn default_opening() -> PoseidonOpening<(), POSEIDON_TREE_DEPTH, 4> {
     // Build a "default" opening
@@ -36,22 +39,36 @@ fn default_opening() -> PoseidonOpening<(), POSEIDON_TREE_DEPTH, 4> {
 /// Create a new wallet meant for tests. It includes a client that will always
 /// return a random anchor (same every time), and the default opening.
 ///
-/// The number of notes available is determined by `note_values`.
+/// The number of notes available is determined by `note_values`, and the
+/// number of self-sent (outgoing) notes by `outgoing_note_values`. The
+/// returned `TestStateClient` is the same one backing the wallet, so tests
+/// can drive it directly, e.g. via `simulate_reorg`, after which the client
+/// returns a second random anchor instead of the first one.
 pub fn mock_wallet<Rng: RngCore + CryptoRng>(
     rng: &mut Rng,
     note_values: &[u64],
-) -> Wallet<TestStore, TestStateClient, TestProverClient> {
+    outgoing_note_values: &[u64],
+) -> (Wallet<TestStore, TestStateClient, TestProverClient>, TestStateClient)
+{
     let store = TestStore::new(rng);
     let psk = store.retrieve_ssk(0).unwrap().public_spend_key();
 
     let notes = new_notes(rng, &psk, note_values);
+    let outgoing_notes = new_notes(rng, &psk, outgoing_note_values);
     let anchor = BlsScalar::random(rng);
+    let forked_anchor = BlsScalar::random(rng);
     let opening = default_opening();
 
-    let state = TestStateClient::new(notes, anchor, opening);
+    let state = TestStateClient::new(
+        notes,
+        outgoing_notes,
+        anchor,
+        forked_anchor,
+        opening,
+    );
     let prover = TestProverClient;
 
-    Wallet::new(store, state, prover)
+    (Wallet::new(store, state.clone(), prover), state)
 }
 
 /// Create a new wallet equivalent in all ways to `mock_wallet`, but serializing
@@ -59,20 +76,30 @@ pub fn mock_wallet<Rng: RngCore + CryptoRng>(
 pub fn mock_canon_wallet<Rng: RngCore + CryptoRng>(
     rng: &mut Rng,
     note_values: &[u64],
-) -> Wallet<TestStore, TestStateClient, RkyvProverClient> {
+    outgoing_note_values: &[u64],
+) -> (Wallet<TestStore, TestStateClient, RkyvProverClient>, TestStateClient)
+{
     let store = TestStore::new(rng);
     let psk = store.retrieve_ssk(0).unwrap().public_spend_key();
 
     let notes = new_notes(rng, &psk, note_values);
+    let outgoing_notes = new_notes(rng, &psk, outgoing_note_values);
     let anchor = BlsScalar::random(rng);
+    let forked_anchor = BlsScalar::random(rng);
     let opening = default_opening();
 
-    let state = TestStateClient::new(notes, anchor, opening);
+    let state = TestStateClient::new(
+        notes,
+        outgoing_notes,
+        anchor,
+        forked_anchor,
+        opening,
+    );
     let prover = RkyvProverClient {
         prover: TestProverClient,
     };
 
-    Wallet::new(store, state, prover)
+    (Wallet::new(store, state.clone(), prover), state)
 }
 
 /// Create a new wallet equivalent in all ways to `mock_wallet`, but serializing
@@ -80,23 +107,34 @@ pub fn mock_canon_wallet<Rng: RngCore + CryptoRng>(
 pub fn mock_serde_wallet<Rng: RngCore + CryptoRng>(
     rng: &mut Rng,
     note_values: &[u64],
-) -> Wallet<TestStore, TestStateClient, SerdeProverClient> {
+    outgoing_note_values: &[u64],
+) -> (Wallet<TestStore, TestStateClient, SerdeProverClient>, TestStateClient)
+{
     let store = TestStore::new(rng);
     let psk = store.retrieve_ssk(0).unwrap().public_spend_key();
 
     let notes = new_notes(rng, &psk, note_values);
+    let outgoing_notes = new_notes(rng, &psk, outgoing_note_values);
     let anchor = BlsScalar::random(rng);
+    let forked_anchor = BlsScalar::random(rng);
     let opening = default_opening();
 
-    let state = TestStateClient::new(notes, anchor, opening);
+    let state = TestStateClient::new(
+        notes,
+        outgoing_notes,
+        anchor,
+        forked_anchor,
+        opening,
+    );
     let prover = SerdeProverClient {
         prover: TestProverClient,
     };
 
-    Wallet::new(store, state, prover)
+    (Wallet::new(store, state.clone(), prover), state)
 }
 
-/// Returns obfuscated notes with the given value.
+/// Returns obfuscated notes with the given values, one per block height
+/// starting at 0, so callers can exercise height-cursored sync.
 fn new_notes<Rng: RngCore + CryptoRng>(
     rng: &mut Rng,
     psk: &PublicSpendKey,
@@ -104,15 +142,54 @@ fn new_notes<Rng: RngCore + CryptoRng>(
 ) -> Vec<EnrichedNote> {
     note_values
         .iter()
-        .map(|val| {
+        .enumerate()
+        .map(|(height, val)| {
             let blinder = JubJubScalar::random(rng);
-            (Note::new(rng, NoteType::Obfuscated, psk, *val, blinder), 0)
+            (
+                Note::new(rng, NoteType::Obfuscated, psk, *val, blinder),
+                height as u64,
+            )
         })
         .collect()
 }
 
+/// Emulates a memory-limited external signer: computes the compact
+/// signing digest for `utx` and signs it for every input away from the
+/// wallet, then injects the resulting signatures back into the
+/// transaction. This is the flow a hardware wallet follows instead of
+/// handling the full serialized `UnprovenTransaction`.
+///
+/// This only plays the external signer's part. The other half -- `Wallet`
+/// constructing the unsigned transaction, handing out the digest, and
+/// accepting signatures back from a caller like this one -- isn't part
+/// of this tree, which has never contained a `Wallet` or any other
+/// library-crate source, only this mock file.
+pub fn mock_external_sign<Rng: RngCore + CryptoRng>(
+    rng: &mut Rng,
+    ssk: &SecretSpendKey,
+    utx: &mut UnprovenTransaction,
+) {
+    let digest = utx.signing_hash();
+
+    let signatures = utx
+        .inputs()
+        .iter()
+        .map(|input| {
+            let sk = ssk.sk_r(input.note().stealth_address());
+            Signature::new(&sk, rng, digest)
+        })
+        .collect();
+
+    utx.set_signatures(signatures);
+}
+
 /// An in-memory seed store.
-#[derive(Debug)]
+///
+/// The seed is wiped from memory as soon as this store is dropped. This
+/// only covers the seed held here; zeroizing the `SecretSpendKey`/
+/// `ViewKey`/`SecretKey` material `Wallet` derives from it during signing
+/// is that crate's responsibility, not this mock's.
+#[derive(Debug, Zeroize, ZeroizeOnDrop)]
 pub struct TestStore {
     seed: [u8; 64],
 }
@@ -129,32 +206,73 @@ impl TestStore {
 impl Store for TestStore {
     type Error = ();
 
-    fn get_seed(&self) -> Result<[u8; 64], Self::Error> {
-        Ok(self.seed)
+    fn get_seed(&self) -> Result<Zeroizing<[u8; 64]>, Self::Error> {
+        Ok(Zeroizing::new(self.seed))
     }
 }
 
 /// A state client that always returns the same notes, anchor, and opening.
+///
+/// `fetch_outgoing_notes` here only hands back whatever `outgoing_notes`
+/// it was constructed with; the `Wallet`-side wiring that makes history
+/// and balance actually account for those self-sent outputs isn't part
+/// of this tree.
+///
+/// Once `simulate_reorg` has been called, `fetch_anchor` and
+/// `fetch_notes_from` report different results: see `simulate_reorg`.
 #[derive(Debug, Clone)]
 pub struct TestStateClient {
     notes: Vec<EnrichedNote>,
+    outgoing_notes: Vec<EnrichedNote>,
     anchor: BlsScalar,
+    // Anchor reported once a reorg has been simulated, distinct from
+    // `anchor` so `fetch_anchor` actually changes and a caller comparing
+    // anchors across syncs can detect the fork, the way it would detect
+    // a real chain reorg.
+    forked_anchor: BlsScalar,
     opening: PoseidonOpening<(), POSEIDON_TREE_DEPTH, 4>,
+    // Height the simulated reorg rolled the chain back to, if one has
+    // been injected: notes above this height are reported as reorged
+    // away and the chain tip is capped at it, so a caller can assert it
+    // rewound to exactly this height rather than merely "some" height.
+    //
+    // This mock cannot exercise a Wallet driver's actual bounded-N-block
+    // rewind -- that logic, and the `Wallet` it lives on, aren't part of
+    // this tree -- it only supplies the fork-point signal such a driver
+    // would react to.
+    reorg_height: Rc<RefCell<Option<u64>>>,
 }
 
 impl TestStateClient {
-    /// Create a new node given the notes, anchor, and opening we will return.
+    /// Create a new node given the notes, outgoing notes, anchor, forked
+    /// anchor, and opening we will return.
     fn new(
         notes: Vec<EnrichedNote>,
+        outgoing_notes: Vec<EnrichedNote>,
         anchor: BlsScalar,
+        forked_anchor: BlsScalar,
         opening: PoseidonOpening<(), POSEIDON_TREE_DEPTH, 4>,
     ) -> Self {
         Self {
             notes,
+            outgoing_notes,
             anchor,
+            forked_anchor,
             opening,
+            reorg_height: Rc::new(RefCell::new(None)),
         }
     }
+
+    /// Simulate a chain reorg that rolls the chain back to `height`: from
+    /// this point on, `fetch_anchor` reports the forked anchor instead of
+    /// the original one, and `fetch_notes_from` reports the chain tip and
+    /// available notes as capped at `height`, as though everything above
+    /// it had been reorged away. A caller can assert against `height`
+    /// directly, e.g. that a sync driver's cursor ends up at exactly that
+    /// value rather than merely "earlier than before".
+    pub fn simulate_reorg(&self, height: u64) {
+        *self.reorg_height.borrow_mut() = Some(height);
+    }
 }
 
 impl StateClient for TestStateClient {
@@ -167,8 +285,43 @@ impl StateClient for TestStateClient {
         Ok(self.notes.clone())
     }
 
+    fn fetch_notes_from(
+        &self,
+        _vk: &ViewKey,
+        from_height: u64,
+    ) -> Result<(Vec<EnrichedNote>, u64), Self::Error> {
+        let cap = self.reorg_height.borrow().unwrap_or(u64::MAX);
+
+        let new_notes: Vec<EnrichedNote> = self
+            .notes
+            .iter()
+            .filter(|(_, height)| *height > from_height && *height <= cap)
+            .cloned()
+            .collect();
+
+        let highest = self
+            .notes
+            .iter()
+            .map(|(_, height)| *height)
+            .filter(|height| *height <= cap)
+            .max()
+            .unwrap_or_else(|| from_height.min(cap));
+
+        Ok((new_notes, highest))
+    }
+
+    fn fetch_outgoing_notes(
+        &self,
+        _ssk: &SecretSpendKey,
+    ) -> Result<Vec<EnrichedNote>, Self::Error> {
+        Ok(self.outgoing_notes.clone())
+    }
+
     fn fetch_anchor(&self) -> Result<BlsScalar, Self::Error> {
-        Ok(self.anchor)
+        Ok(match *self.reorg_height.borrow() {
+            Some(_) => self.forked_anchor,
+            None => self.anchor,
+        })
     }
 
     fn fetch_existing_nullifiers(
@@ -194,18 +347,30 @@ impl StateClient for TestStateClient {
     }
 }
 
+/// A prover that proves in-process by attaching a default proof, and whose
+/// propagation does nothing.
+///
+/// This only exercises the `ProverClient` side of the `prove`/`propagate`
+/// split; the `Wallet::prove_transaction`/`propagate_transaction` pair
+/// and the convenience that chains them live in the wallet crate these
+/// mocks are built against, not in this file.
 #[derive(Debug)]
 pub struct TestProverClient;
 
 impl ProverClient for TestProverClient {
     type Error = ();
-    fn compute_proof_and_propagate(
+
+    fn prove(
         &self,
         utx: &UnprovenTransaction,
     ) -> Result<Transaction, Self::Error> {
         Ok(utx.clone().prove(Proof::default()))
     }
 
+    fn propagate(&self, _tx: &Transaction) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     fn request_stct_proof(
         &self,
         _fee: &Fee,
@@ -236,7 +401,7 @@ pub struct RkyvProverClient {
 impl ProverClient for RkyvProverClient {
     type Error = ();
 
-    fn compute_proof_and_propagate(
+    fn prove(
         &self,
         utx: &UnprovenTransaction,
     ) -> Result<Transaction, Self::Error> {
@@ -256,7 +421,11 @@ impl ProverClient for RkyvProverClient {
             "Encoded and decoded transaction should be equal"
         );
 
-        self.prover.compute_proof_and_propagate(utx)
+        self.prover.prove(utx)
+    }
+
+    fn propagate(&self, tx: &Transaction) -> Result<(), Self::Error> {
+        self.prover.propagate(tx)
     }
 
     fn request_stct_proof(
@@ -291,7 +460,7 @@ pub struct SerdeProverClient {
 impl ProverClient for SerdeProverClient {
     type Error = ();
 
-    fn compute_proof_and_propagate(
+    fn prove(
         &self,
         utx: &UnprovenTransaction,
     ) -> Result<Transaction, Self::Error> {
@@ -322,7 +491,11 @@ impl ProverClient for SerdeProverClient {
         assert_eq!(utx.crossover(), utx_clone.crossover());
         assert_eq!(utx.call(), utx_clone.call());
 
-        self.prover.compute_proof_and_propagate(utx)
+        self.prover.prove(utx)
+    }
+
+    fn propagate(&self, tx: &Transaction) -> Result<(), Self::Error> {
+        self.prover.propagate(tx)
     }
 
     fn request_stct_proof(